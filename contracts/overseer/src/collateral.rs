@@ -1,12 +1,60 @@
+use cosmwasm_bignumber::{Decimal256, Uint256};
 use cosmwasm_std::{
     log, to_binary, Api, CanonicalAddr, CosmosMsg, Decimal, Env, Extern, HandleResponse,
     HandleResult, HumanAddr, Querier, StdError, StdResult, Storage, Uint128, WasmMsg,
 };
 
 use crate::state::{
-    read_config, read_loan, read_whitelist_item, store_loan, Config, Loan, WhitelistItem,
+    read_config, read_loan, read_state, read_whitelist_item, store_config, store_loan,
+    store_state, validate_fee_config, Config, Loan, State, WhitelistItem,
 };
-use moneymarket::{load_oracle_price, CustodyHandleMsg, MarketHandleMsg, OraclePriceResponse};
+use moneymarket::{
+    decimal256_division, decimal_multiplication, load_borrow_rate, load_oracle_price,
+    uint256_to_uint128, BorrowLimitResponse, CollateralInfo, CustodyHandleMsg, LoanInfoResponse,
+    MarketHandleMsg, OraclePriceResponse,
+};
+
+/// Project `State::cumulative_borrow_rate` forward to `block_height` without persisting it,
+/// for the read-only queries below that have no mutable `Extern` to write through.
+fn project_cumulative_borrow_rate<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    block_height: u64,
+) -> StdResult<Decimal> {
+    let config: Config = read_config(&deps.storage)?;
+    let state: State = read_state(&deps.storage)?;
+
+    if block_height <= state.last_accrued {
+        return Ok(state.cumulative_borrow_rate);
+    }
+
+    let interest_model = deps.api.human_address(&config.interest_model_contract)?;
+    let borrow_rate = load_borrow_rate(deps, &interest_model)?;
+
+    let blocks_elapsed = block_height - state.last_accrued;
+    let interest_factor =
+        decimal_multiplication(borrow_rate.rate, Decimal::from_ratio(blocks_elapsed, 1u64));
+
+    Ok(state.cumulative_borrow_rate
+        + decimal_multiplication(state.cumulative_borrow_rate, interest_factor))
+}
+
+/// Accrue interest since the last update, advancing and persisting `State::cumulative_borrow_rate`,
+/// and return the refreshed index.
+fn accrue_interest<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: &Env,
+) -> StdResult<Decimal> {
+    let mut state: State = read_state(&deps.storage)?;
+    let cumulative_borrow_rate = project_cumulative_borrow_rate(deps, env.block.height)?;
+
+    if env.block.height > state.last_accrued {
+        state.cumulative_borrow_rate = cumulative_borrow_rate;
+        state.last_accrued = env.block.height;
+        store_state(&mut deps.storage, &state)?;
+    }
+
+    Ok(cumulative_borrow_rate)
+}
 
 pub fn handle_lock_collateral<S: Storage, A: Api, Q: Querier>(
     deps: &mut Extern<S, A, Q>,
@@ -69,9 +117,12 @@ pub fn handle_unlock_collateral<S: Storage, A: Api, Q: Querier>(
     // Underflow check is done in sub_collateral
     loan.sub_collateral(collaterals_raw)?;
 
+    let cumulative_borrow_rate = accrue_interest(deps, &env)?;
+    loan.accrue_interest(cumulative_borrow_rate)?;
+
     // Compute borrow limit with collaterals except unlock target collaterals
-    let borrow_limit = compute_borrow_limit(deps, &loan.collaterals)?;
-    if borrow_limit < loan.borrow_amount {
+    let borrow_limit = compute_borrow_limit(deps, env.block.time, &loan.collaterals)?;
+    if borrow_limit < Uint256::from(loan.borrow_amount) {
         return Err(StdError::generic_err(
             "Cannot unlock collateral more than minimum LTV",
         ));
@@ -109,7 +160,7 @@ pub fn handle_unlock_collateral<S: Storage, A: Api, Q: Querier>(
 }
 
 pub fn handle_borrow<S: Storage, A: Api, Q: Querier>(
-    deps: &Extern<S, A, Q>,
+    deps: &mut Extern<S, A, Q>,
     env: Env,
     amount: Uint128,
 ) -> HandleResult {
@@ -117,13 +168,18 @@ pub fn handle_borrow<S: Storage, A: Api, Q: Querier>(
 
     let borrower_raw = deps.api.canonical_address(&env.message.sender)?;
     let mut loan: Loan = read_loan(&deps.storage, &borrower_raw);
+
+    let cumulative_borrow_rate = accrue_interest(deps, &env)?;
+    loan.accrue_interest(cumulative_borrow_rate)?;
     loan.borrow_amount += amount;
 
-    let borrow_limit = compute_borrow_limit(deps, &loan.collaterals)?;
-    if borrow_limit < loan.borrow_amount {
+    let borrow_limit = compute_borrow_limit(deps, env.block.time, &loan.collaterals)?;
+    if borrow_limit < Uint256::from(loan.borrow_amount) {
         return Err(StdError::generic_err("Cannot borrow more than minimum LTV"));
     }
 
+    store_loan(&mut deps.storage, &borrower_raw, &loan)?;
+
     Ok(HandleResponse {
         messages: vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: deps.api.human_address(&config.market_contract)?,
@@ -142,26 +198,200 @@ pub fn handle_borrow<S: Storage, A: Api, Q: Querier>(
     })
 }
 
+/// Convert a 256-bit `value` (in `base_denom`) into a token amount at `price`, narrowing to
+/// `Uint128` only here. A zero price returns zero rather than dividing by it.
+fn value_to_token_amount(value: Uint256, price: Decimal) -> StdResult<Uint128> {
+    if price.is_zero() {
+        return Ok(Uint128::zero());
+    }
+
+    uint256_to_uint128(decimal256_division(value, Decimal256::from(price)))
+}
+
 pub fn handle_liquidiate_collateral<S: Storage, A: Api, Q: Querier>(
-    _deps: &mut Extern<S, A, Q>,
-    _env: Env,
-    _borrower: HumanAddr,
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    borrower: HumanAddr,
 ) -> HandleResult {
-    Ok(HandleResponse::default())
+    let config: Config = read_config(&deps.storage)?;
+    let borrower_raw = deps.api.canonical_address(&borrower)?;
+    let mut loan: Loan = read_loan(&deps.storage, &borrower_raw);
+
+    if loan.collaterals.is_empty() {
+        return Err(StdError::generic_err("Borrower has no collateral locked"));
+    }
+
+    let cumulative_borrow_rate = accrue_interest(deps, &env)?;
+    loan.accrue_interest(cumulative_borrow_rate)?;
+
+    // Fetch the collateral basket once; discount_collateral_values reuses it for the health
+    // check below instead of re-querying the oracle.
+    let (collateral_values, total_collateral_value) =
+        compute_collateral_values(deps, env.block.time, &loan.collaterals)?;
+
+    let borrow_limit = discount_collateral_values(deps, &collateral_values)?;
+    if borrow_limit >= Uint256::from(loan.borrow_amount) {
+        return Err(StdError::generic_err(
+            "Cannot liquidate a safely collateralized loan",
+        ));
+    }
+
+    // A fresh-but-zero oracle price (a de-pegged/crashed whitelisted asset, say) isn't
+    // caught by the staleness check above but leaves nothing of value to seize; reject
+    // explicitly instead of dividing by a zero `total_collateral_value` below.
+    if total_collateral_value == Uint256::zero() {
+        return Err(StdError::generic_err(
+            "Cannot liquidate a position whose collateral has zero value",
+        ));
+    }
+
+    // Close at most `close_factor` of the outstanding debt per liquidation call, in 256-bit
+    // space for the same overflow reason as `compute_collateral_values`.
+    let liquidation_amount_256 =
+        Uint256::from(loan.borrow_amount) * Decimal256::from(config.close_factor);
+    let liquidation_amount = uint256_to_uint128(liquidation_amount_256)?;
+    let liquidation_value =
+        liquidation_amount_256 * Decimal256::from(Decimal::one() + config.liquidation_bonus);
+    // Protocol cut, seized on top of `liquidation_value` and routed to `config.fee_config`
+    // instead of the liquidator
+    let fee_value = liquidation_amount_256 * Decimal256::from(config.liquidation_fee);
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut seized_collaterals: Vec<(CanonicalAddr, Uint128)> = vec![];
+    for collateral_value in collateral_values.iter() {
+        let share =
+            Decimal256::from_ratio(Uint256::from(collateral_value.value), total_collateral_value);
+        let custody_contract =
+            deps.api
+                .human_address(&read_whitelist_item(&deps.storage, &collateral_value.token)?.custody_contract)?;
+        let mut remaining = collateral_value.amount;
+        let mut total_seized = Uint128::zero();
+
+        let seize_value = liquidation_value * share;
+        let seize_amount = std::cmp::min(value_to_token_amount(seize_value, collateral_value.price)?, remaining);
+        if !seize_amount.is_zero() {
+            messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: custody_contract.clone(),
+                send: vec![],
+                msg: to_binary(&CustodyHandleMsg::LiquidateCollateral {
+                    liquidator: env.message.sender.clone(),
+                    borrower: borrower.clone(),
+                    amount: seize_amount,
+                })?,
+            }));
+            remaining = (remaining - seize_amount)?;
+            total_seized += seize_amount;
+        }
+
+        for (recipient, weight) in config.fee_config.iter() {
+            if remaining.is_zero() {
+                break;
+            }
+
+            let recipient_value = fee_value * share * Decimal256::from(*weight);
+            let recipient_amount = std::cmp::min(
+                value_to_token_amount(recipient_value, collateral_value.price)?,
+                remaining,
+            );
+            if recipient_amount.is_zero() {
+                continue;
+            }
+
+            messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: custody_contract.clone(),
+                send: vec![],
+                msg: to_binary(&CustodyHandleMsg::DistributeLiquidationFee {
+                    recipient: deps.api.human_address(recipient)?,
+                    borrower: borrower.clone(),
+                    amount: recipient_amount,
+                })?,
+            }));
+            remaining = (remaining - recipient_amount)?;
+            total_seized += recipient_amount;
+        }
+
+        if !total_seized.is_zero() {
+            seized_collaterals.push((collateral_value.token.clone(), total_seized));
+        }
+    }
+
+    loan.sub_collateral(seized_collaterals)?;
+    loan.subtract_borrow_amount(liquidation_amount)?;
+    store_loan(&mut deps.storage, &borrower_raw, &loan)?;
+
+    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: deps.api.human_address(&config.market_contract)?,
+        send: vec![],
+        msg: to_binary(&MarketHandleMsg::RepayStableFromLiquidation {
+            borrower: borrower.clone(),
+            amount: liquidation_amount,
+        })?,
+    }));
+
+    Ok(HandleResponse {
+        messages,
+        log: vec![
+            log("action", "liquidate_collateral"),
+            log("liquidator", env.message.sender),
+            log("borrower", borrower),
+            log("liquidation_amount", liquidation_amount),
+        ],
+        data: None,
+    })
 }
 
-fn compute_borrow_limit<S: Storage, A: Api, Q: Querier>(
+/// Replace `Config::fee_config`, the weighted split of the liquidation protocol fee.
+/// Owner-only; rejects weights that don't sum to exactly `Decimal::one()`.
+pub fn handle_update_fee_config<S: Storage, A: Api, Q: Querier>(
+    deps: &mut Extern<S, A, Q>,
+    env: Env,
+    fee_config: Vec<(HumanAddr, Decimal)>,
+) -> HandleResult {
+    let mut config: Config = read_config(&deps.storage)?;
+    if deps.api.canonical_address(&env.message.sender)? != config.owner {
+        return Err(StdError::unauthorized());
+    }
+
+    let fee_config_raw: Vec<(CanonicalAddr, Decimal)> = fee_config
+        .iter()
+        .map(|(recipient, weight)| Ok((deps.api.canonical_address(recipient)?, *weight)))
+        .collect::<StdResult<Vec<(CanonicalAddr, Decimal)>>>()?;
+    validate_fee_config(&fee_config_raw)?;
+
+    config.fee_config = fee_config_raw;
+    store_config(&mut deps.storage, &config)?;
+
+    Ok(HandleResponse {
+        messages: vec![],
+        log: vec![log("action", "update_fee_config")],
+        data: None,
+    })
+}
+
+/// Per-collateral valuation (in `base_denom`, undiscounted by LTV) used to proportionally
+/// allocate seizure across a borrower's collateral basket during liquidation.
+struct CollateralValue {
+    token: CanonicalAddr,
+    amount: Uint128,
+    price: Decimal,
+    value: Uint128,
+}
+
+/// Value `collaterals` against the oracle, rejecting any price older than
+/// `config.price_timeframe` as of `block_time`. The basket total is accumulated and returned
+/// in 256-bit space since a large basket summed in plain `Uint128` can overflow; only each
+/// collateral's own value is narrowed to `Uint128`.
+fn compute_collateral_values<S: Storage, A: Api, Q: Querier>(
     deps: &Extern<S, A, Q>,
-    collaterals: &Vec<(CanonicalAddr, Uint128)>,
-) -> StdResult<Uint128> {
+    block_time: u64,
+    collaterals: &[(CanonicalAddr, Uint128)],
+) -> StdResult<(Vec<CollateralValue>, Uint256)> {
     let config: Config = read_config(&deps.storage)?;
     let oracle_contract = deps.api.human_address(&config.oracle_contract)?;
 
-    let mut borrow_limit: Uint128 = Uint128::zero();
-    for collateral in collaterals.iter() {
-        let collateral_token = collateral.0.clone();
-        let collateral_amount = collateral.1;
-
+    let mut collateral_values: Vec<CollateralValue> = Vec::with_capacity(collaterals.len());
+    let mut total_value: Uint256 = Uint256::zero();
+    for (collateral_token, collateral_amount) in collaterals.iter() {
         let price: OraclePriceResponse = load_oracle_price(
             &deps,
             &oracle_contract,
@@ -169,11 +399,973 @@ fn compute_borrow_limit<S: Storage, A: Api, Q: Querier>(
             collateral_token.to_string(),
         )?;
 
-        // TODO check price last_updated
+        if price.last_updated_base + config.price_timeframe < block_time
+            || price.last_updated_quote + config.price_timeframe < block_time
+        {
+            return Err(StdError::generic_err(format!(
+                "Price is too old: last updated at base {}, quote {}",
+                price.last_updated_base, price.last_updated_quote
+            )));
+        }
+
+        let value_256 = Uint256::from(*collateral_amount) * Decimal256::from(price.rate);
+        total_value += value_256;
+        collateral_values.push(CollateralValue {
+            token: collateral_token.clone(),
+            amount: *collateral_amount,
+            price: price.rate,
+            value: uint256_to_uint128(value_256)?,
+        });
+    }
 
-        let item: WhitelistItem = read_whitelist_item(&deps.storage, &collateral.0)?;
-        borrow_limit += collateral_amount * item.ltv * price.rate;
+    Ok((collateral_values, total_value))
+}
+
+/// Discount already-fetched `collateral_values` by each token's whitelisted LTV. Split out
+/// of `compute_borrow_limit` so callers that already hold a `compute_collateral_values`
+/// result (`handle_liquidiate_collateral`, `query_loan_info`) don't pay for a second oracle
+/// fetch just to get a borrow limit.
+fn discount_collateral_values<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    collateral_values: &[CollateralValue],
+) -> StdResult<Uint256> {
+    let mut borrow_limit: Uint256 = Uint256::zero();
+    for collateral_value in collateral_values.iter() {
+        let item: WhitelistItem = read_whitelist_item(&deps.storage, &collateral_value.token)?;
+        borrow_limit += Uint256::from(collateral_value.value) * Decimal256::from(item.ltv);
     }
 
     Ok(borrow_limit)
+}
+
+/// Maximum `borrow_amount` a loan with the given `collaterals` can sustain. Callers compare
+/// this against a loan's `borrow_amount` by widening it into the same 256-bit space.
+fn compute_borrow_limit<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    block_time: u64,
+    collaterals: &Vec<(CanonicalAddr, Uint128)>,
+) -> StdResult<Uint256> {
+    let (collateral_values, _) = compute_collateral_values(deps, block_time, collaterals)?;
+    discount_collateral_values(deps, &collateral_values)
+}
+
+/// Maximum `borrow_amount` `borrower`'s currently locked collateral can sustain, as of
+/// `block_time`. The limit depends only on the collateral and oracle prices; the pending
+/// interest projected onto the loan as of `block_height` (via
+/// `project_cumulative_borrow_rate`) does not change it.
+pub fn query_borrow_limit<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    borrower: HumanAddr,
+    block_time: u64,
+    block_height: u64,
+) -> StdResult<BorrowLimitResponse> {
+    let borrower_raw = deps.api.canonical_address(&borrower)?;
+    let mut loan: Loan = read_loan(&deps.storage, &borrower_raw);
+    loan.accrue_interest(project_cumulative_borrow_rate(deps, block_height)?)?;
+
+    let borrow_limit = compute_borrow_limit(deps, block_time, &loan.collaterals)?;
+
+    Ok(BorrowLimitResponse {
+        borrower,
+        borrow_limit: uint256_to_uint128(borrow_limit)?,
+    })
+}
+
+/// `borrower`'s full position as of `block_time`/`block_height`: outstanding debt (with
+/// pending interest projected, see `query_borrow_limit`), borrow limit, and a per-collateral
+/// valuation breakdown.
+pub fn query_loan_info<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    borrower: HumanAddr,
+    block_time: u64,
+    block_height: u64,
+) -> StdResult<LoanInfoResponse> {
+    let borrower_raw = deps.api.canonical_address(&borrower)?;
+    let mut loan: Loan = read_loan(&deps.storage, &borrower_raw);
+    loan.accrue_interest(project_cumulative_borrow_rate(deps, block_height)?)?;
+
+    let (collateral_values, _) = compute_collateral_values(deps, block_time, &loan.collaterals)?;
+    let borrow_limit = discount_collateral_values(deps, &collateral_values)?;
+
+    let collaterals = collateral_values
+        .iter()
+        .map(|collateral_value| {
+            Ok(CollateralInfo {
+                collateral_token: deps.api.human_address(&collateral_value.token)?,
+                amount: collateral_value.amount,
+                price: collateral_value.price,
+                value: collateral_value.value,
+            })
+        })
+        .collect::<StdResult<Vec<CollateralInfo>>>()?;
+
+    Ok(LoanInfoResponse {
+        borrower,
+        borrow_amount: loan.borrow_amount,
+        borrow_limit: uint256_to_uint128(borrow_limit)?,
+        liquidatable: borrow_limit < Uint256::from(loan.borrow_amount),
+        collaterals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{store_config, store_whitelist_item};
+    use crate::testing::mock_dependencies;
+    use cosmwasm_std::testing::mock_env;
+
+    /// Baseline `Config` shared by the tests below; override individual fields with
+    /// struct-update syntax, e.g. `Config { liquidation_fee: Decimal::percent(10), ..mock_config(&deps.api) }`.
+    fn mock_config<A: Api>(api: &A) -> Config {
+        Config {
+            owner: api.canonical_address(&HumanAddr::from("owner0000")).unwrap(),
+            oracle_contract: api.canonical_address(&HumanAddr::from("oracle0000")).unwrap(),
+            market_contract: api.canonical_address(&HumanAddr::from("market0000")).unwrap(),
+            interest_model_contract: api
+                .canonical_address(&HumanAddr::from("interest0000"))
+                .unwrap(),
+            base_denom: "uusd".to_string(),
+            price_timeframe: 60,
+            close_factor: Decimal::percent(50),
+            liquidation_bonus: Decimal::percent(5),
+            liquidation_fee: Decimal::zero(),
+            fee_config: vec![],
+        }
+    }
+
+    #[test]
+    fn liquidation_rejected_on_healthy_loan() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("token0001"))
+            .unwrap();
+        let custody_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("custody0001"))
+            .unwrap();
+
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+        store_whitelist_item(
+            &mut deps.storage,
+            &token_raw,
+            &WhitelistItem {
+                name: "token0001".to_string(),
+                symbol: "TOK".to_string(),
+                custody_contract: custody_raw,
+                ltv: Decimal::percent(50),
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("liquidator0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw, Uint128(1_000_000))],
+                borrow_amount: Uint128(400_000),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        let res = handle_liquidiate_collateral(&mut deps, env, HumanAddr::from("borrower0000"));
+        match res {
+            Err(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "Cannot liquidate a safely collateralized loan")
+            }
+            _ => panic!("expected a healthy-loan rejection"),
+        }
+    }
+
+    #[test]
+    fn liquidation_seizes_collateral_and_repays_debt() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("token0001"))
+            .unwrap();
+        let custody_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("custody0001"))
+            .unwrap();
+
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+        store_whitelist_item(
+            &mut deps.storage,
+            &token_raw,
+            &WhitelistItem {
+                name: "token0001".to_string(),
+                symbol: "TOK".to_string(),
+                custody_contract: custody_raw,
+                ltv: Decimal::percent(50),
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("liquidator0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw, Uint128(1_000_000))],
+                borrow_amount: Uint128(600_000),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        let res =
+            handle_liquidiate_collateral(&mut deps, env, HumanAddr::from("borrower0000")).unwrap();
+
+        // one LiquidateCollateral message to the custody contract, one RepayStableFromLiquidation
+        // to the market contract
+        assert_eq!(2, res.messages.len());
+
+        let loan = read_loan(&deps.storage, &borrower_raw);
+        assert_eq!(Uint128(300_000), loan.borrow_amount);
+        assert_eq!(Uint128(685_000), loan.collaterals[0].1);
+    }
+
+    #[test]
+    fn liquidation_rejects_collateral_with_zero_value() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("token0001"))
+            .unwrap();
+        let custody_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("custody0001"))
+            .unwrap();
+
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+        store_whitelist_item(
+            &mut deps.storage,
+            &token_raw,
+            &WhitelistItem {
+                name: "token0001".to_string(),
+                symbol: "TOK".to_string(),
+                custody_contract: custody_raw,
+                ltv: Decimal::percent(50),
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("liquidator0000", &[]);
+        // A fresh price of zero - the collateral has crashed/de-pegged but isn't stale.
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::zero(), env.block.time, env.block.time),
+        )]);
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw, Uint128(1_000_000))],
+                borrow_amount: Uint128(600_000),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        let res = handle_liquidiate_collateral(&mut deps, env, HumanAddr::from("borrower0000"));
+        match res {
+            Err(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "Cannot liquidate a position whose collateral has zero value")
+            }
+            _ => panic!("expected a zero-value collateral rejection"),
+        }
+    }
+
+    #[test]
+    fn liquidation_stays_exact_for_a_large_borrow_amount() {
+        // Same magnitude as `accrue_interest_stays_exact_for_a_large_borrow_amount`.
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("token0001"))
+            .unwrap();
+        let custody_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("custody0001"))
+            .unwrap();
+
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+        store_whitelist_item(
+            &mut deps.storage,
+            &token_raw,
+            &WhitelistItem {
+                name: "token0001".to_string(),
+                symbol: "TOK".to_string(),
+                custody_contract: custody_raw,
+                // Zero LTV guarantees the position is liquidatable regardless of collateral size.
+                ltv: Decimal::zero(),
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("liquidator0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+
+        let borrow_amount = 300_000_000_000_000_000_000_000_000_000_000_000_000u128;
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw, Uint128(borrow_amount))],
+                borrow_amount: Uint128(borrow_amount),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        let res =
+            handle_liquidiate_collateral(&mut deps, env, HumanAddr::from("borrower0000")).unwrap();
+        assert_eq!(2, res.messages.len());
+
+        let loan = read_loan(&deps.storage, &borrower_raw);
+        assert_eq!(
+            Uint128(150_000_000_000_000_000_000_000_000_000_000_000_000u128),
+            loan.borrow_amount
+        );
+        assert_eq!(
+            Uint128(142_500_000_000_000_000_000_000_000_000_000_000_000u128),
+            loan.collaterals[0].1
+        );
+    }
+
+    #[test]
+    fn interest_accrues_across_multiple_blocks() {
+        let mut deps = mock_dependencies(20, &[]);
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+        deps.querier.with_borrow_rate(Decimal::permille(1));
+
+        let mut env = mock_env("someone0000", &[]);
+        env.block.height = 100;
+        let index_after_first = accrue_interest(&mut deps, &env).unwrap();
+        assert_eq!(Decimal::permille(1100), index_after_first);
+
+        env.block.height = 200;
+        let index_after_second = accrue_interest(&mut deps, &env).unwrap();
+        assert_eq!(Decimal::from_ratio(1210u128, 1000u128), index_after_second);
+
+        // Re-accruing at the same height is a no-op
+        let index_unchanged = accrue_interest(&mut deps, &env).unwrap();
+        assert_eq!(index_after_second, index_unchanged);
+    }
+
+    #[test]
+    fn accrued_interest_tightens_the_borrow_limit() {
+        let mut loan = Loan {
+            collaterals: vec![],
+            borrow_amount: Uint128(500_000),
+            borrow_index: Decimal::one(),
+        };
+
+        // Global index capitalized 50% since this loan last synced
+        loan.accrue_interest(Decimal::percent(150)).unwrap();
+
+        assert_eq!(Uint128(750_000), loan.borrow_amount);
+        assert_eq!(Decimal::percent(150), loan.borrow_index);
+    }
+
+    #[test]
+    fn accrue_interest_stays_exact_for_a_large_borrow_amount() {
+        // Same magnitude used in `compute_borrow_limit_stays_exact_for_large_collateral_baskets`;
+        // large enough to overflow the old `Uint128 * Decimal` multiply.
+        let borrow_amount = 300_000_000_000_000_000_000_000_000_000_000_000_000u128;
+        let mut loan = Loan {
+            collaterals: vec![],
+            borrow_amount: Uint128(borrow_amount),
+            borrow_index: Decimal::one(),
+        };
+
+        loan.accrue_interest(Decimal::percent(110)).unwrap();
+
+        assert_eq!(
+            Uint128(330_000_000_000_000_000_000_000_000_000_000_000_000u128),
+            loan.borrow_amount
+        );
+        assert_eq!(Decimal::percent(110), loan.borrow_index);
+    }
+
+    fn setup_borrow_test(
+        deps: &mut Extern<
+            cosmwasm_std::testing::MockStorage,
+            cosmwasm_std::testing::MockApi,
+            crate::testing::mock_querier::WasmMockQuerier,
+        >,
+    ) -> CanonicalAddr {
+        let token_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("token0001"))
+            .unwrap();
+        let custody_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("custody0001"))
+            .unwrap();
+
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+        store_whitelist_item(
+            &mut deps.storage,
+            &token_raw,
+            &WhitelistItem {
+                name: "token0001".to_string(),
+                symbol: "TOK".to_string(),
+                custody_contract: custody_raw,
+                ltv: Decimal::percent(50),
+            },
+        )
+        .unwrap();
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw.clone(), Uint128(1_000_000))],
+                borrow_amount: Uint128::zero(),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        token_raw
+    }
+
+    #[test]
+    fn borrow_accepts_a_fresh_oracle_price() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = setup_borrow_test(&mut deps);
+
+        let env = mock_env("borrower0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+
+        handle_borrow(&mut deps, env, Uint128(100_000)).unwrap();
+    }
+
+    #[test]
+    fn borrow_rejects_a_stale_oracle_price() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = setup_borrow_test(&mut deps);
+
+        let env = mock_env("borrower0000", &[]);
+        let stale_time = env.block.time - 61;
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), stale_time, stale_time),
+        )]);
+
+        let res = handle_borrow(&mut deps, env, Uint128(100_000));
+        match res {
+            Err(StdError::GenericErr { msg, .. }) => assert!(msg.starts_with("Price is too old")),
+            _ => panic!("expected a stale price rejection"),
+        }
+    }
+
+    #[test]
+    fn compute_borrow_limit_stays_exact_for_large_collateral_baskets() {
+        let mut deps = mock_dependencies(20, &[]);
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+
+        let env = mock_env("someone0000", &[]);
+
+        // Each collateral is worth more than half of `u128::MAX` at 100% LTV and a 1:1
+        // price, so summing their values the way the old `Uint128` accumulator did would
+        // overflow. The `Uint256` accumulator in `compute_borrow_limit` holds the exact sum.
+        let large_amount = Uint128(u128::MAX / 2 + 1_000_000);
+        let mut collaterals = vec![];
+        let mut oracle_prices = vec![];
+        for name in ["token0001", "token0002"] {
+            let token_raw = deps.api.canonical_address(&HumanAddr::from(name)).unwrap();
+            let custody_raw = deps
+                .api
+                .canonical_address(&HumanAddr::from(format!("custody_{}", name)))
+                .unwrap();
+            store_whitelist_item(
+                &mut deps.storage,
+                &token_raw,
+                &WhitelistItem {
+                    name: name.to_string(),
+                    symbol: "TOK".to_string(),
+                    custody_contract: custody_raw,
+                    ltv: Decimal::one(),
+                },
+            )
+            .unwrap();
+            oracle_prices.push((
+                ("uusd".to_string(), token_raw.to_string()),
+                (Decimal::one(), env.block.time, env.block.time),
+            ));
+            collaterals.push((token_raw, large_amount));
+        }
+        deps.querier.with_oracle_price(
+            &oracle_prices
+                .iter()
+                .map(|(k, v)| (k, v))
+                .collect::<Vec<_>>(),
+        );
+
+        let borrow_limit = compute_borrow_limit(&deps, env.block.time, &collaterals).unwrap();
+        assert_eq!(Uint256::from(large_amount) + Uint256::from(large_amount), borrow_limit);
+    }
+
+    #[test]
+    fn compute_collateral_values_rejects_a_stale_oracle_price() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = setup_borrow_test(&mut deps);
+
+        let env = mock_env("someone0000", &[]);
+        let stale_time = env.block.time - 61;
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), stale_time, stale_time),
+        )]);
+
+        let res = compute_collateral_values(&deps, env.block.time, &[(token_raw, Uint128(1_000_000))]);
+        match res {
+            Err(StdError::GenericErr { msg, .. }) => assert!(msg.starts_with("Price is too old")),
+            _ => panic!("expected a stale price rejection"),
+        }
+    }
+
+    #[test]
+    fn query_loan_info_stays_exact_for_large_collateral_baskets() {
+        let mut deps = mock_dependencies(20, &[]);
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+
+        let env = mock_env("someone0000", &[]);
+
+        // Same two-collateral fixture as `compute_borrow_limit_stays_exact_for_large_collateral_baskets`:
+        // each collateral is worth more than half of `u128::MAX` at a 1:1 price, so summing
+        // their values the way the old `Uint128` accumulator in `compute_collateral_values`
+        // did would overflow and panic. A 1% LTV keeps the aggregate `borrow_limit` itself
+        // within `Uint128` range so the response can narrow cleanly.
+        let large_amount = Uint128(u128::MAX / 2 + 1_000_000);
+        let mut collaterals = vec![];
+        let mut oracle_prices = vec![];
+        for name in ["token0001", "token0002"] {
+            let token_raw = deps.api.canonical_address(&HumanAddr::from(name)).unwrap();
+            let custody_raw = deps
+                .api
+                .canonical_address(&HumanAddr::from(format!("custody_{}", name)))
+                .unwrap();
+            store_whitelist_item(
+                &mut deps.storage,
+                &token_raw,
+                &WhitelistItem {
+                    name: name.to_string(),
+                    symbol: "TOK".to_string(),
+                    custody_contract: custody_raw,
+                    ltv: Decimal::percent(1),
+                },
+            )
+            .unwrap();
+            oracle_prices.push((
+                ("uusd".to_string(), token_raw.to_string()),
+                (Decimal::one(), env.block.time, env.block.time),
+            ));
+            collaterals.push((token_raw, large_amount));
+        }
+        deps.querier.with_oracle_price(
+            &oracle_prices
+                .iter()
+                .map(|(k, v)| (k, v))
+                .collect::<Vec<_>>(),
+        );
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals,
+                borrow_amount: Uint128::zero(),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        let res = query_loan_info(
+            &deps,
+            HumanAddr::from("borrower0000"),
+            env.block.time,
+            env.block.height,
+        )
+        .unwrap();
+        assert_eq!(2, res.collaterals.len());
+        for collateral in res.collaterals.iter() {
+            assert_eq!(large_amount, collateral.value);
+        }
+    }
+
+    #[test]
+    fn uint256_to_uint128_rejects_values_that_dont_fit() {
+        let fits = Uint256::from(Uint128(u128::MAX));
+        assert_eq!(Uint128(u128::MAX), uint256_to_uint128(fits).unwrap());
+
+        let overflows = Uint256::from(Uint128(u128::MAX)) + Uint256::from(1u64);
+        assert!(uint256_to_uint128(overflows).is_err());
+    }
+
+    #[test]
+    fn query_borrow_limit_matches_locked_collateral() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = setup_borrow_test(&mut deps);
+
+        let env = mock_env("borrower0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+
+        let res = query_borrow_limit(
+            &deps,
+            HumanAddr::from("borrower0000"),
+            env.block.time,
+            env.block.height,
+        )
+        .unwrap();
+        assert_eq!(HumanAddr::from("borrower0000"), res.borrower);
+        // 1_000_000 collateral at 50% LTV and a 1:1 price
+        assert_eq!(Uint128(500_000), res.borrow_limit);
+    }
+
+    #[test]
+    fn query_loan_info_reports_a_liquidatable_position() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("token0001"))
+            .unwrap();
+        let custody_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("custody0001"))
+            .unwrap();
+
+        store_config(&mut deps.storage, &mock_config(&deps.api)).unwrap();
+        store_whitelist_item(
+            &mut deps.storage,
+            &token_raw,
+            &WhitelistItem {
+                name: "token0001".to_string(),
+                symbol: "TOK".to_string(),
+                custody_contract: custody_raw,
+                ltv: Decimal::percent(50),
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("borrower0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw, Uint128(1_000_000))],
+                borrow_amount: Uint128(600_000),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        let res = query_loan_info(
+            &deps,
+            HumanAddr::from("borrower0000"),
+            env.block.time,
+            env.block.height,
+        )
+        .unwrap();
+        assert_eq!(Uint128(600_000), res.borrow_amount);
+        assert_eq!(Uint128(500_000), res.borrow_limit);
+        assert!(res.liquidatable);
+        assert_eq!(1, res.collaterals.len());
+        assert_eq!(HumanAddr::from("token0001"), res.collaterals[0].collateral_token);
+        assert_eq!(Uint128(1_000_000), res.collaterals[0].value);
+    }
+
+    #[test]
+    fn queries_project_pending_interest_onto_an_untouched_loan() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = setup_borrow_test(&mut deps);
+
+        let env = mock_env("borrower0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+        deps.querier.with_borrow_rate(Decimal::percent(10));
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        // Borrow against a stale index, then never touch the loan again - interest
+        // capitalizes lazily on the next handle_*/liquidation call, not automatically.
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw, Uint128(1_000_000))],
+                borrow_amount: Uint128(400_000),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        // 10 blocks elapsed since genesis at a 10%-per-block rate: index goes from 1.0 to
+        // 2.0, so the projected debt is double the stored `borrow_amount`.
+        let accrued_block_height = 10;
+
+        let borrow_limit_res = query_borrow_limit(
+            &deps,
+            HumanAddr::from("borrower0000"),
+            env.block.time,
+            accrued_block_height,
+        )
+        .unwrap();
+        // 1_000_000 collateral at 50% LTV and a 1:1 price - unaffected by the borrower's debt
+        assert_eq!(Uint128(500_000), borrow_limit_res.borrow_limit);
+
+        let loan_info_res = query_loan_info(
+            &deps,
+            HumanAddr::from("borrower0000"),
+            env.block.time,
+            accrued_block_height,
+        )
+        .unwrap();
+        assert_eq!(Uint128(800_000), loan_info_res.borrow_amount);
+        assert!(loan_info_res.liquidatable);
+
+        // Stored state is untouched - these are read-only projections
+        let loan = read_loan(&deps.storage, &borrower_raw);
+        assert_eq!(Uint128(400_000), loan.borrow_amount);
+    }
+
+    #[test]
+    fn liquidation_fee_splits_across_weighted_recipients() {
+        let mut deps = mock_dependencies(20, &[]);
+        let token_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("token0001"))
+            .unwrap();
+        let custody_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("custody0001"))
+            .unwrap();
+        let insurance_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("insurance0000"))
+            .unwrap();
+        let treasury_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("treasury0000"))
+            .unwrap();
+
+        store_config(
+            &mut deps.storage,
+            &Config {
+                liquidation_fee: Decimal::percent(10),
+                fee_config: vec![
+                    (insurance_raw, Decimal::percent(70)),
+                    (treasury_raw, Decimal::percent(30)),
+                ],
+                ..mock_config(&deps.api)
+            },
+        )
+        .unwrap();
+        store_whitelist_item(
+            &mut deps.storage,
+            &token_raw,
+            &WhitelistItem {
+                name: "token0001".to_string(),
+                symbol: "TOK".to_string(),
+                custody_contract: custody_raw,
+                ltv: Decimal::percent(50),
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("liquidator0000", &[]);
+        deps.querier.with_oracle_price(&[(
+            &("uusd".to_string(), token_raw.to_string()),
+            &(Decimal::one(), env.block.time, env.block.time),
+        )]);
+
+        let borrower_raw = deps
+            .api
+            .canonical_address(&HumanAddr::from("borrower0000"))
+            .unwrap();
+        store_loan(
+            &mut deps.storage,
+            &borrower_raw,
+            &Loan {
+                collaterals: vec![(token_raw, Uint128(1_000_000))],
+                borrow_amount: Uint128(600_000),
+                borrow_index: Decimal::one(),
+            },
+        )
+        .unwrap();
+
+        let res =
+            handle_liquidiate_collateral(&mut deps, env, HumanAddr::from("borrower0000")).unwrap();
+
+        // liquidator seize + insurance seize + treasury seize + RepayStableFromLiquidation
+        assert_eq!(4, res.messages.len());
+
+        fn seize_amount(msg: &CosmosMsg) -> Uint128 {
+            match msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                    match cosmwasm_std::from_binary(msg).unwrap() {
+                        CustodyHandleMsg::LiquidateCollateral { amount, .. } => amount,
+                        CustodyHandleMsg::DistributeLiquidationFee { amount, .. } => amount,
+                        _ => panic!("expected a LiquidateCollateral/DistributeLiquidationFee message"),
+                    }
+                }
+                _ => panic!("expected a Wasm execute message"),
+            }
+        }
+
+        // close_factor(50%) * bonus(1.05) = 315_000 to the liquidator
+        assert_eq!(Uint128(315_000), seize_amount(&res.messages[0]));
+        // liquidation_amount(300_000) * liquidation_fee(10%) * weight(70%) = 21_000
+        assert_eq!(Uint128(21_000), seize_amount(&res.messages[1]));
+        // liquidation_amount(300_000) * liquidation_fee(10%) * weight(30%) = 9_000
+        assert_eq!(Uint128(9_000), seize_amount(&res.messages[2]));
+
+        let loan = read_loan(&deps.storage, &borrower_raw);
+        assert_eq!(Uint128(300_000), loan.borrow_amount);
+        assert_eq!(Uint128(655_000), loan.collaterals[0].1);
+    }
+
+    #[test]
+    fn update_fee_config_rejects_weights_that_dont_sum_to_one() {
+        let mut deps = mock_dependencies(20, &[]);
+        store_config(
+            &mut deps.storage,
+            &Config {
+                liquidation_fee: Decimal::percent(10),
+                fee_config: vec![],
+                ..mock_config(&deps.api)
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("owner0000", &[]);
+        let res = handle_update_fee_config(
+            &mut deps,
+            env,
+            vec![
+                (HumanAddr::from("insurance0000"), Decimal::percent(70)),
+                (HumanAddr::from("treasury0000"), Decimal::percent(20)),
+            ],
+        );
+        assert!(res.is_err());
+
+        let unchanged = read_config(&deps.storage).unwrap();
+        assert!(unchanged.fee_config.is_empty());
+    }
+
+    #[test]
+    fn update_fee_config_rejects_non_owner() {
+        let mut deps = mock_dependencies(20, &[]);
+        store_config(
+            &mut deps.storage,
+            &Config {
+                liquidation_fee: Decimal::percent(10),
+                fee_config: vec![],
+                ..mock_config(&deps.api)
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("not_owner0000", &[]);
+        let res = handle_update_fee_config(
+            &mut deps,
+            env,
+            vec![(HumanAddr::from("insurance0000"), Decimal::one())],
+        );
+        match res {
+            Err(StdError::Unauthorized { .. }) => {}
+            _ => panic!("expected an unauthorized rejection"),
+        }
+    }
+
+    #[test]
+    fn update_fee_config_accepts_normalized_weights() {
+        let mut deps = mock_dependencies(20, &[]);
+        store_config(
+            &mut deps.storage,
+            &Config {
+                liquidation_fee: Decimal::percent(10),
+                fee_config: vec![],
+                ..mock_config(&deps.api)
+            },
+        )
+        .unwrap();
+
+        let env = mock_env("owner0000", &[]);
+        handle_update_fee_config(
+            &mut deps,
+            env,
+            vec![
+                (HumanAddr::from("insurance0000"), Decimal::percent(70)),
+                (HumanAddr::from("treasury0000"), Decimal::percent(30)),
+            ],
+        )
+        .unwrap();
+
+        let config = read_config(&deps.storage).unwrap();
+        assert_eq!(2, config.fee_config.len());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub mod collateral;
+pub mod state;
+
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,190 @@
+use cosmwasm_bignumber::{Decimal256, Uint256};
+use cosmwasm_std::{CanonicalAddr, Decimal, StdError, StdResult, Storage, Uint128};
+use cosmwasm_storage::{bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket};
+use moneymarket::{decimal256_division, uint256_to_uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+static KEY_CONFIG: &[u8] = b"config";
+static KEY_STATE: &[u8] = b"state";
+static PREFIX_WHITELIST: &[u8] = b"whitelist";
+static PREFIX_LOAN: &[u8] = b"loan";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub owner: CanonicalAddr,
+    pub oracle_contract: CanonicalAddr,
+    pub market_contract: CanonicalAddr,
+    pub interest_model_contract: CanonicalAddr,
+    pub base_denom: String,
+    /// Maximum age, in seconds, an oracle price is allowed to have before
+    /// `compute_borrow_limit` rejects it as stale
+    pub price_timeframe: u64,
+    /// Maximum fraction of a loan's outstanding debt that can be closed in a single
+    /// `handle_liquidiate_collateral` call
+    pub close_factor: Decimal,
+    /// Extra share of seized collateral value awarded to the liquidator on top of the
+    /// debt repaid, e.g. 0.05 == 5%
+    pub liquidation_bonus: Decimal,
+    /// Protocol cut of the liquidated debt value, seized on top of `liquidation_bonus` and
+    /// distributed across `fee_config` instead of going to the liquidator
+    pub liquidation_fee: Decimal,
+    /// Weighted recipients of `liquidation_fee`. Weights must sum to exactly `Decimal::one()`;
+    /// see `validate_fee_config`
+    pub fee_config: Vec<(CanonicalAddr, Decimal)>,
+}
+
+/// Check that `fee_config`'s weights normalize to exactly `1.0`, e.g. before accepting an
+/// admin update to `Config::fee_config`.
+pub fn validate_fee_config(fee_config: &[(CanonicalAddr, Decimal)]) -> StdResult<()> {
+    let total_weight = fee_config
+        .iter()
+        .fold(Decimal::zero(), |acc, (_, weight)| acc + *weight);
+
+    if total_weight != Decimal::one() {
+        return Err(StdError::generic_err(format!(
+            "fee_config weights must sum to 1.0, got {}",
+            total_weight
+        )));
+    }
+
+    Ok(())
+}
+
+pub fn store_config<S: Storage>(storage: &mut S, config: &Config) -> StdResult<()> {
+    singleton(storage, KEY_CONFIG).save(config)
+}
+
+pub fn read_config<S: Storage>(storage: &S) -> StdResult<Config> {
+    singleton_read(storage, KEY_CONFIG).load()
+}
+
+/// Global interest-accrual state, updated by `accrue_interest` whenever the block height has
+/// advanced past `last_accrued`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct State {
+    /// Monotonically increasing index capitalizing the per-block borrow rate since genesis
+    pub cumulative_borrow_rate: Decimal,
+    pub last_accrued: u64,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            cumulative_borrow_rate: Decimal::one(),
+            last_accrued: 0,
+        }
+    }
+}
+
+pub fn store_state<S: Storage>(storage: &mut S, state: &State) -> StdResult<()> {
+    singleton(storage, KEY_STATE).save(state)
+}
+
+pub fn read_state<S: Storage>(storage: &S) -> StdResult<State> {
+    singleton_read(storage, KEY_STATE).load().or(Ok(State::default()))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WhitelistItem {
+    pub name: String,
+    pub symbol: String,
+    pub custody_contract: CanonicalAddr,
+    pub ltv: Decimal,
+}
+
+pub fn store_whitelist_item<S: Storage>(
+    storage: &mut S,
+    collateral_token: &CanonicalAddr,
+    whitelist_item: &WhitelistItem,
+) -> StdResult<()> {
+    let mut whitelist_bucket: Bucket<S, WhitelistItem> = bucket(PREFIX_WHITELIST, storage);
+    whitelist_bucket.save(collateral_token.as_slice(), whitelist_item)
+}
+
+pub fn read_whitelist_item<S: Storage>(
+    storage: &S,
+    collateral_token: &CanonicalAddr,
+) -> StdResult<WhitelistItem> {
+    let whitelist_bucket: ReadonlyBucket<S, WhitelistItem> = bucket_read(PREFIX_WHITELIST, storage);
+    whitelist_bucket.load(collateral_token.as_slice())
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct Loan {
+    pub collaterals: Vec<(CanonicalAddr, Uint128)>,
+    pub borrow_amount: Uint128,
+    /// Snapshot of `State::cumulative_borrow_rate` the last time this loan's debt was
+    /// restated. Zero means the loan has never borrowed and has nothing to capitalize.
+    pub borrow_index: Decimal,
+}
+
+impl Loan {
+    /// Capitalize interest accrued since `borrow_index` was last snapshotted by restating
+    /// `borrow_amount` against the new global index, then advance the snapshot. Restates in
+    /// 256-bit space to avoid overflowing a large `borrow_amount`.
+    pub fn accrue_interest(&mut self, cumulative_borrow_rate: Decimal) -> StdResult<()> {
+        if !self.borrow_index.is_zero() && !self.borrow_amount.is_zero() {
+            let accrued = Uint256::from(self.borrow_amount) * Decimal256::from(cumulative_borrow_rate);
+            let restated = decimal256_division(accrued, Decimal256::from(self.borrow_index));
+            self.borrow_amount = uint256_to_uint128(restated)?;
+        }
+
+        self.borrow_index = cumulative_borrow_rate;
+        Ok(())
+    }
+
+    pub fn add_collateral(&mut self, collaterals: Vec<(CanonicalAddr, Uint128)>) {
+        for collateral in collaterals {
+            let position = self.collaterals.iter().position(|c| c.0 == collateral.0);
+            match position {
+                Some(idx) => self.collaterals[idx].1 += collateral.1,
+                None => self.collaterals.push(collateral),
+            }
+        }
+    }
+
+    pub fn sub_collateral(
+        &mut self,
+        collaterals: Vec<(CanonicalAddr, Uint128)>,
+    ) -> StdResult<()> {
+        for collateral in collaterals {
+            let position = self.collaterals.iter().position(|c| c.0 == collateral.0);
+            match position {
+                Some(idx) => {
+                    self.collaterals[idx].1 = (self.collaterals[idx].1 - collateral.1)?;
+                    if self.collaterals[idx].1.is_zero() {
+                        self.collaterals.remove(idx);
+                    }
+                }
+                None => {
+                    return Err(cosmwasm_std::StdError::generic_err(
+                        "Cannot subtract collateral that is not locked",
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reduce outstanding `borrow_amount` by `amount`, e.g. the debt repaid by a liquidation.
+    pub fn subtract_borrow_amount(&mut self, amount: Uint128) -> StdResult<()> {
+        self.borrow_amount = (self.borrow_amount - amount)?;
+        Ok(())
+    }
+}
+
+pub fn store_loan<S: Storage>(
+    storage: &mut S,
+    borrower: &CanonicalAddr,
+    loan: &Loan,
+) -> StdResult<()> {
+    let mut loan_bucket: Bucket<S, Loan> = bucket(PREFIX_LOAN, storage);
+    loan_bucket.save(borrower.as_slice(), loan)
+}
+
+pub fn read_loan<S: Storage>(storage: &S, borrower: &CanonicalAddr) -> Loan {
+    let loan_bucket: ReadonlyBucket<S, Loan> = bucket_read(PREFIX_LOAN, storage);
+    loan_bucket.load(borrower.as_slice()).unwrap_or_default()
+}
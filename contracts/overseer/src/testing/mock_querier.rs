@@ -0,0 +1,144 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    from_binary, from_slice, to_binary, Api, Coin, Decimal, Extern, HumanAddr, Querier,
+    QuerierResult, QueryRequest, SystemError, WasmQuery,
+};
+use std::collections::HashMap;
+
+use moneymarket::interest_model::BorrowRateResponse;
+use moneymarket::oracle::OraclePriceResponse;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Query the exchange rate between `base` and `quote` to the oracle contract
+    Price { base: String, quote: String },
+    /// Query borrow rate to interest model contract
+    BorrowRate {},
+}
+
+/// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies
+/// this uses our WasmMockQuerier.
+pub fn mock_dependencies(
+    canonical_length: usize,
+    contract_balance: &[Coin],
+) -> Extern<MockStorage, MockApi, WasmMockQuerier> {
+    let contract_addr = HumanAddr::from(MOCK_CONTRACT_ADDR);
+    let custom_querier: WasmMockQuerier = WasmMockQuerier::new(MockQuerier::new(&[(
+        &contract_addr,
+        contract_balance,
+    )]));
+
+    Extern {
+        storage: MockStorage::default(),
+        api: MockApi::new(canonical_length),
+        querier: custom_querier,
+    }
+}
+
+pub struct WasmMockQuerier {
+    base: MockQuerier,
+    oracle_price_querier: OraclePriceQuerier,
+    borrow_rate_querier: BorrowRateQuerier,
+}
+
+#[derive(Clone, Default)]
+pub struct OraclePriceQuerier {
+    // (base, quote) -> (rate, last_updated_base, last_updated_quote)
+    oracle_price: HashMap<(String, String), (Decimal, u64, u64)>,
+}
+
+impl OraclePriceQuerier {
+    pub fn new(oracle_price: &[(&(String, String), &(Decimal, u64, u64))]) -> Self {
+        OraclePriceQuerier {
+            oracle_price: oracle_price_to_map(oracle_price),
+        }
+    }
+}
+
+pub(crate) fn oracle_price_to_map(
+    oracle_price: &[(&(String, String), &(Decimal, u64, u64))],
+) -> HashMap<(String, String), (Decimal, u64, u64)> {
+    let mut oracle_price_map: HashMap<(String, String), (Decimal, u64, u64)> = HashMap::new();
+    for (base_quote, price) in oracle_price.iter() {
+        oracle_price_map.insert((*base_quote).clone(), **price);
+    }
+
+    oracle_price_map
+}
+
+#[derive(Clone, Default)]
+pub struct BorrowRateQuerier {
+    rate: Decimal,
+}
+
+impl BorrowRateQuerier {
+    pub fn new(rate: Decimal) -> Self {
+        BorrowRateQuerier { rate }
+    }
+}
+
+impl Querier for WasmMockQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest<cosmwasm_std::Empty> = match from_slice(bin_request) {
+            Ok(v) => v,
+            Err(e) => {
+                return Err(SystemError::InvalidRequest {
+                    error: format!("Parsing query request: {}", e),
+                    request: bin_request.into(),
+                })
+            }
+        };
+        self.handle_query(&request)
+    }
+}
+
+impl WasmMockQuerier {
+    pub fn handle_query(&self, request: &QueryRequest<cosmwasm_std::Empty>) -> QuerierResult {
+        match &request {
+            QueryRequest::Wasm(WasmQuery::Smart { msg, .. }) => match from_binary(&msg).unwrap() {
+                QueryMsg::Price { base, quote } => {
+                    match self.oracle_price_querier.oracle_price.get(&(base, quote)) {
+                        Some((rate, last_updated_base, last_updated_quote)) => {
+                            Ok(to_binary(&OraclePriceResponse {
+                                rate: *rate,
+                                last_updated_base: *last_updated_base,
+                                last_updated_quote: *last_updated_quote,
+                            }))
+                        }
+                        None => Err(SystemError::InvalidRequest {
+                            error: "No oracle price exists".to_string(),
+                            request: msg.as_slice().into(),
+                        }),
+                    }
+                }
+                QueryMsg::BorrowRate {} => Ok(to_binary(&BorrowRateResponse {
+                    rate: self.borrow_rate_querier.rate,
+                })),
+            },
+            _ => self.base.handle_query(request),
+        }
+    }
+}
+
+impl WasmMockQuerier {
+    pub fn new(base: MockQuerier) -> Self {
+        WasmMockQuerier {
+            base,
+            oracle_price_querier: OraclePriceQuerier::default(),
+            borrow_rate_querier: BorrowRateQuerier::default(),
+        }
+    }
+
+    // configure the oracle price mock querier
+    pub fn with_oracle_price(&mut self, oracle_price: &[(&(String, String), &(Decimal, u64, u64))]) {
+        self.oracle_price_querier = OraclePriceQuerier::new(oracle_price);
+    }
+
+    pub fn with_borrow_rate(&mut self, rate: Decimal) {
+        self.borrow_rate_querier = BorrowRateQuerier::new(rate);
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod mock_querier;
+
+pub use mock_querier::mock_dependencies;
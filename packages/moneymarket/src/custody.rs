@@ -0,0 +1,25 @@
+use cosmwasm_std::{HumanAddr, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustodyHandleMsg {
+    /// Deposit collateral on behalf of `borrower`
+    LockCollateral { borrower: HumanAddr, amount: Uint128 },
+    /// Withdraw collateral back to `borrower`
+    UnlockCollateral { borrower: HumanAddr, amount: Uint128 },
+    /// Seize `amount` of collateral from `borrower` and send it to `liquidator`
+    LiquidateCollateral {
+        liquidator: HumanAddr,
+        borrower: HumanAddr,
+        amount: Uint128,
+    },
+    /// Seize `amount` of collateral from `borrower` and send it to `recipient` as a protocol
+    /// liquidation fee, distinct from the liquidator's cut paid via `LiquidateCollateral`
+    DistributeLiquidationFee {
+        recipient: HumanAddr,
+        borrower: HumanAddr,
+        amount: Uint128,
+    },
+}
@@ -0,0 +1,8 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DistributionParamsResponse {
+    pub emission_rate: cosmwasm_std::Decimal,
+    pub target_deposit_rate: cosmwasm_std::Decimal,
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::{
+    to_binary, Api, Decimal, Extern, HumanAddr, Querier, QueryRequest, StdResult, Storage,
+    WasmQuery,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Query the per-block borrow interest rate
+    BorrowRate {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BorrowRateResponse {
+    pub rate: Decimal,
+}
+
+/// Queries the interest model contract for the current per-block borrow rate.
+pub fn load_borrow_rate<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    interest_model_contract: &HumanAddr,
+) -> StdResult<BorrowRateResponse> {
+    let borrow_rate: BorrowRateResponse =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: HumanAddr::from(interest_model_contract),
+            msg: to_binary(&QueryMsg::BorrowRate {})?,
+        }))?;
+
+    Ok(borrow_rate)
+}
@@ -0,0 +1,20 @@
+pub mod custody;
+pub mod distribution_model;
+pub mod interest_model;
+pub mod market;
+pub mod math;
+pub mod oracle;
+pub mod overseer;
+
+pub use custody::CustodyHandleMsg;
+pub use distribution_model::DistributionParamsResponse;
+pub use interest_model::{load_borrow_rate, BorrowRateResponse};
+pub use market::{EpochStateResponse, LoanAmountResponse, MarketHandleMsg};
+pub use math::{
+    decimal256_division, decimal_division, decimal_multiplication, decimal_subtraction,
+    reverse_decimal, uint256_to_uint128,
+};
+pub use oracle::{load_oracle_price, OraclePriceResponse};
+pub use overseer::{
+    BorrowLimitResponse, CollateralInfo, LoanInfoResponse, OverseerHandleMsg, QueryMsg,
+};
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Decimal, HumanAddr, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketHandleMsg {
+    /// Disburse `amount` of the stable denom to `borrower`
+    ExecuteLoan { borrower: HumanAddr, amount: Uint128 },
+    /// Repay `amount` of `borrower`'s debt, e.g. from seized liquidation proceeds
+    RepayStableFromLiquidation { borrower: HumanAddr, amount: Uint128 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EpochStateResponse {
+    pub exchange_rate: Decimal,
+    pub aterra_supply: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LoanAmountResponse {
+    pub borrower: HumanAddr,
+    pub loan_amount: Uint128,
+}
@@ -0,0 +1,48 @@
+use cosmwasm_bignumber::{Decimal256, Uint256};
+use cosmwasm_std::{Decimal, StdError, StdResult, Uint128};
+
+const DECIMAL_FRACTION: Uint128 = Uint128(1_000_000_000_000_000_000u128);
+
+pub fn reverse_decimal(decimal: Decimal) -> Decimal {
+    if decimal.is_zero() {
+        return Decimal::zero();
+    }
+
+    Decimal::from_ratio(DECIMAL_FRACTION, decimal * DECIMAL_FRACTION)
+}
+
+pub fn decimal_multiplication(a: Decimal, b: Decimal) -> Decimal {
+    Decimal::from_ratio(a * DECIMAL_FRACTION, DECIMAL_FRACTION) * b
+}
+
+pub fn decimal_subtraction(a: Decimal, b: Decimal) -> StdResult<Decimal> {
+    Ok(Decimal::from_ratio(
+        (a * DECIMAL_FRACTION - b * DECIMAL_FRACTION)?,
+        DECIMAL_FRACTION,
+    ))
+}
+
+/// Divide `amount` by `decimal`, e.g. convert a value denominated amount back into a token
+/// amount given that token's price.
+pub fn decimal_division(amount: Uint128, decimal: Decimal) -> Uint128 {
+    amount * reverse_decimal(decimal)
+}
+
+/// 256-bit analog of `decimal_division`, for dividends already accumulated in `Uint256`
+/// space (e.g. restating accrued interest) that would overflow a plain `Uint128` multiply.
+pub fn decimal256_division(amount: Uint256, decimal: Decimal256) -> Uint256 {
+    amount * (Decimal256::one() / decimal)
+}
+
+/// Narrow a `Uint256` accumulated in 256-bit space back down to the `Uint128` used at
+/// message and storage boundaries, erroring instead of silently truncating if it doesn't fit.
+pub fn uint256_to_uint128(value: Uint256) -> StdResult<Uint128> {
+    if value > Uint256::from(Uint128(u128::MAX)) {
+        return Err(StdError::generic_err(format!(
+            "Value {} overflows Uint128",
+            value
+        )));
+    }
+
+    Ok(value.into())
+}
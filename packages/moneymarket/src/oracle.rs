@@ -0,0 +1,36 @@
+use cosmwasm_std::{
+    to_binary, Api, Decimal, Extern, HumanAddr, Querier, QueryRequest, StdResult, Storage,
+    WasmQuery,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Price { base: String, quote: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OraclePriceResponse {
+    pub rate: Decimal,
+    pub last_updated_base: u64,
+    pub last_updated_quote: u64,
+}
+
+/// Queries the oracle contract for the exchange rate between `base` and `quote`.
+pub fn load_oracle_price<S: Storage, A: Api, Q: Querier>(
+    deps: &Extern<S, A, Q>,
+    oracle_contract: &HumanAddr,
+    base: String,
+    quote: String,
+) -> StdResult<OraclePriceResponse> {
+    let price: OraclePriceResponse = deps
+        .querier
+        .query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: HumanAddr::from(oracle_contract),
+            msg: to_binary(&QueryMsg::Price { base, quote })?,
+        }))?;
+
+    Ok(price)
+}
@@ -0,0 +1,58 @@
+use cosmwasm_std::{Decimal, HumanAddr, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OverseerHandleMsg {
+    /// Replace `Config::fee_config`, the weighted split of the liquidation protocol fee.
+    /// Owner-only; weights must sum to exactly 1.0.
+    UpdateFeeConfig {
+        fee_config: Vec<(HumanAddr, Decimal)>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Maximum `borrow_amount` the borrower's currently locked collateral can sustain.
+    /// `block_time`/`block_height` are required because the overseer's query entry point
+    /// has no `Env`; `block_time` drives the oracle staleness check. The limit depends only
+    /// on the collateral, so `block_height` does not change the response.
+    BorrowLimit {
+        borrower: HumanAddr,
+        block_time: u64,
+        block_height: u64,
+    },
+    /// The borrower's full position: outstanding debt, borrow limit, and a per-collateral
+    /// valuation breakdown a keeper can use to decide what to seize.
+    LoanInfo {
+        borrower: HumanAddr,
+        block_time: u64,
+        block_height: u64,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BorrowLimitResponse {
+    pub borrower: HumanAddr,
+    pub borrow_limit: Uint128,
+}
+
+/// Valuation of a single collateral token within a `LoanInfoResponse`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollateralInfo {
+    pub collateral_token: HumanAddr,
+    pub amount: Uint128,
+    pub price: Decimal,
+    pub value: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LoanInfoResponse {
+    pub borrower: HumanAddr,
+    pub borrow_amount: Uint128,
+    pub borrow_limit: Uint128,
+    pub collaterals: Vec<CollateralInfo>,
+    pub liquidatable: bool,
+}